@@ -1,4 +1,4 @@
-use std::io::{Error, ErrorKind};
+use std::io::Error;
 
 use crate::algorithms::CRHError;
 
@@ -28,6 +28,6 @@ impl From<Error> for CommitmentError {
 
 impl From<CommitmentError> for Error {
     fn from(error: CommitmentError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+        Error::other(error.to_string())
     }
 }
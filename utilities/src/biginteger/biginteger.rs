@@ -3,13 +3,15 @@ use crate::{
     bytes::{FromBytes, ToBytes},
     io::{Read, Result as IoResult, Write},
     rand::UniformRand,
+    vec,
+    Vec,
 };
 
+use core::fmt::{Debug, Display};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::fmt::{Debug, Display};
 
 bigint_impl!(BigInteger64, 1);
 bigint_impl!(BigInteger128, 2);
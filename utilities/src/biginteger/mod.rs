@@ -1,6 +1,7 @@
 #[macro_use]
 mod macros;
 
+#[allow(clippy::module_inception)]
 pub mod biginteger;
 pub use self::biginteger::*;
 
@@ -3,35 +3,36 @@ use crate::{biginteger::*, rand::UniformRand};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 
+#[allow(clippy::eq_op)]
 fn biginteger_arithmetic_test<B: BigInteger>(a: B, b: B, zero: B) {
     // zero == zero
     assert_eq!(zero, zero);
 
     // zero.is_zero() == true
-    assert_eq!(zero.is_zero(), true);
+    assert!(zero.is_zero());
 
     // a == a
     assert_eq!(a, a);
 
     // a + 0 = a
-    let mut a0_add = a.clone();
+    let mut a0_add = a;
     a0_add.add_nocarry(&zero);
     assert_eq!(a0_add, a);
 
     // a - 0 = a
-    let mut a0_sub = a.clone();
+    let mut a0_sub = a;
     a0_sub.sub_noborrow(&zero);
     assert_eq!(a0_sub, a);
 
     // a - a = 0
-    let mut aa_sub = a.clone();
+    let mut aa_sub = a;
     aa_sub.sub_noborrow(&a);
     assert_eq!(aa_sub, zero);
 
     // a + b = b + a
-    let mut ab_add = a.clone();
+    let mut ab_add = a;
     ab_add.add_nocarry(&b);
-    let mut ba_add = b.clone();
+    let mut ba_add = b;
     ba_add.add_nocarry(&a);
     assert_eq!(ab_add, ba_add);
 }
@@ -98,3 +99,59 @@ fn test_biginteger768() {
 fn test_biginteger832() {
     test_biginteger(BigInteger832::new([0u64; 13]));
 }
+
+// Limbs are written least-significant first, each in little-endian order.
+test_round_trip!(
+    test_biginteger64_round_trip,
+    BigInteger64,
+    (BigInteger64::new([0x0102_0304_0506_0708]), [
+        0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01
+    ])
+);
+test_round_trip!(
+    test_biginteger128_round_trip,
+    BigInteger128,
+    (BigInteger128::new([1, 2]), [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+    ])
+);
+test_round_trip!(
+    test_biginteger256_round_trip,
+    BigInteger256,
+    (BigInteger256::new([1, 2, 3, 4]), [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+    ])
+);
+test_round_trip!(
+    test_biginteger320_round_trip,
+    BigInteger320,
+    (
+        BigInteger320::from(0x0201),
+        [&[0x01, 0x02][..], &[0x00; 38][..]].concat()
+    )
+);
+test_round_trip!(
+    test_biginteger384_round_trip,
+    BigInteger384,
+    (
+        BigInteger384::from(0x0201),
+        [&[0x01, 0x02][..], &[0x00; 46][..]].concat()
+    )
+);
+test_round_trip!(
+    test_biginteger768_round_trip,
+    BigInteger768,
+    (
+        BigInteger768::from(0x0201),
+        [&[0x01, 0x02][..], &[0x00; 94][..]].concat()
+    )
+);
+test_round_trip!(
+    test_biginteger832_round_trip,
+    BigInteger832,
+    (
+        BigInteger832::from(0x0201),
+        [&[0x01, 0x02][..], &[0x00; 102][..]].concat()
+    )
+);
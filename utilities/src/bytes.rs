@@ -12,6 +12,17 @@ pub trait ToBytes {
 pub trait FromBytes: Sized {
     /// Reads `Self` from `reader`.
     fn read<R: Read>(reader: R) -> IoResult<Self>;
+
+    /// Reads `Self` from `bytes`, failing if any bytes are left over.
+    ///
+    /// This is opt-in: use it instead of `read` when `bytes` holds exactly one encoded value.
+    fn read_strict(mut bytes: &[u8]) -> IoResult<Self> {
+        let value = Self::read(&mut bytes)?;
+        if !bytes.is_empty() {
+            return Err(error("FromBytes::read_strict found trailing bytes"));
+        }
+        Ok(value)
+    }
 }
 
 macro_rules! array_bytes {
@@ -303,8 +314,89 @@ impl<'a, T: 'a + ToBytes> ToBytes for &'a T {
 
 #[cfg(test)]
 mod test {
-    use super::ToBytes;
-    use crate::Vec;
+    use super::{FromBytes, ToBytes};
+    use crate::{rand::test_rng, Vec};
+
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    const ITERATIONS: usize = 10000;
+
+    fn read_mutated_bytes<T: FromBytes + ToBytes>(rng: &mut impl Rng)
+    where
+        Standard: Distribution<T>,
+    {
+        let value: T = rng.gen();
+        let mut bytes = to_bytes![value].unwrap();
+
+        // Any truncation of a valid encoding must be rejected.
+        let truncated = &bytes[..rng.gen_range(0, bytes.len())];
+        assert!(T::read(truncated).is_err());
+
+        // A mutated encoding must be rejected or serialize back to the same bytes.
+        let index = rng.gen_range(0, bytes.len());
+        bytes[index] = rng.gen::<u8>();
+        if let Ok(value) = T::read(&bytes[..]) {
+            assert_eq!(to_bytes![value].unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_unit() {
+        let bytes = to_bytes![()].unwrap();
+        assert!(bytes.is_empty());
+        <()>::read(&bytes[..]).unwrap();
+    }
+
+    test_round_trip!(test_round_trip_bool, bool, (false, [0]), (true, [1]));
+    test_round_trip!(test_round_trip_u8, u8, (0xab, [0xab]));
+    test_round_trip!(test_round_trip_u16, u16, (0x0102, [0x02, 0x01]));
+    test_round_trip!(test_round_trip_u32, u32, (0x0102_0304, [0x04, 0x03, 0x02, 0x01]));
+    test_round_trip!(
+        test_round_trip_u64,
+        u64,
+        (0x0102_0304_0506_0708, [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01])
+    );
+    test_round_trip!(
+        test_round_trip_i64,
+        i64,
+        (-2, [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff])
+    );
+    test_round_trip!(test_round_trip_u8_array, [u8; 3], ([1, 2, 3], [0x01, 0x02, 0x03]));
+    test_round_trip!(
+        test_round_trip_u16_array,
+        [u16; 2],
+        ([1, 0x0203], [0x01, 0x00, 0x03, 0x02])
+    );
+    test_round_trip!(
+        test_round_trip_u32_array,
+        [u32; 1],
+        ([0x0102_0304], [0x04, 0x03, 0x02, 0x01])
+    );
+    test_round_trip!(
+        test_round_trip_u64_array,
+        [u64; 1],
+        ([1], [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])
+    );
+
+    #[test]
+    fn test_read_strict() {
+        // `error` drops its message without `std`, so only the failure is checked.
+        assert_eq!(0x0102, u16::read_strict(&[0x02, 0x01]).unwrap());
+        assert!(u16::read_strict(&[0x02]).is_err());
+        assert!(u16::read_strict(&[0x02, 0x01, 0x00]).is_err());
+        assert!(<()>::read_strict(&[0x00]).is_err());
+    }
+
+    #[test]
+    fn test_read_bool_non_canonical() {
+        for byte in 2..=u8::MAX {
+            assert!(bool::read(&[byte][..]).is_err());
+        }
+    }
+
     #[test]
     fn test_macro_empty() {
         let array: Vec<u8> = vec![];
@@ -327,4 +419,20 @@ mod test {
         actual_bytes.extend_from_slice(&array3);
         assert_eq!(bytes, actual_bytes);
     }
+
+    #[test]
+    fn test_read_mutated_bytes() {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            read_mutated_bytes::<u8>(&mut rng);
+            read_mutated_bytes::<u16>(&mut rng);
+            read_mutated_bytes::<u32>(&mut rng);
+            read_mutated_bytes::<u64>(&mut rng);
+            read_mutated_bytes::<i64>(&mut rng);
+            read_mutated_bytes::<bool>(&mut rng);
+            read_mutated_bytes::<[u8; 32]>(&mut rng);
+            read_mutated_bytes::<[u64; 4]>(&mut rng);
+        }
+    }
 }
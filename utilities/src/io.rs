@@ -54,7 +54,7 @@ impl Write for &mut [u8] {
     #[inline]
     fn write_all(&mut self, data: &[u8]) -> Result<()> {
         let amt = cmp::min(data.len(), self.len());
-        let (a, b) = mem::replace(self, &mut []).split_at_mut(amt);
+        let (a, b) = mem::take(self).split_at_mut(amt);
         a.copy_from_slice(&data[..amt]);
         *self = b;
 
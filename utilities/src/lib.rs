@@ -1,5 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
 #[cfg(all(test, not(feature = "std")))]
 #[macro_use]
 extern crate std;
@@ -14,6 +17,39 @@ pub use alloc::{boxed::Box, format, vec, vec::Vec};
 #[doc(hidden)]
 pub use std::{boxed::Box, format, vec, vec::Vec};
 
+/// Checks that each value serializes to its recorded bytes and reads back,
+/// then round trips random values of the same type.
+#[cfg(test)]
+macro_rules! test_round_trip {
+    ($name:ident, $ty:ty, $(($value:expr, $expected:expr)),+) => {
+        #[test]
+        fn $name() {
+            use $crate::bytes::{FromBytes, ToBytes};
+            use ::rand::Rng;
+
+            $(
+                let value: $ty = $value;
+                let bytes = $crate::to_bytes![value].unwrap();
+                assert_eq!(&bytes[..], &$expected[..]);
+
+                let mut reader = &bytes[..];
+                assert_eq!(value, <$ty>::read(&mut reader).unwrap());
+                assert!(reader.is_empty());
+            )+
+
+            let mut rng = $crate::rand::test_rng();
+            for _ in 0..1000 {
+                let value: $ty = rng.gen();
+                let bytes = $crate::to_bytes![value].unwrap();
+
+                let mut reader = &bytes[..];
+                assert_eq!(value, <$ty>::read(&mut reader).unwrap());
+                assert!(reader.is_empty());
+            }
+        }
+    };
+}
+
 pub mod biginteger;
 pub mod bititerator;
 #[macro_use]
@@ -34,7 +70,7 @@ fn error(_msg: &'static str) -> io::Error {
 
 #[cfg(feature = "std")]
 fn error(msg: &'static str) -> io::Error {
-    io::Error::new(io::ErrorKind::Other, msg)
+    io::Error::other(msg)
 }
 
 #[macro_export]
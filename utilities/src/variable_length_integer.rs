@@ -1,8 +1,12 @@
 use crate::{
     error,
     io::{Read, Result as IoResult},
+    vec,
+    Vec,
 };
 
+use core::convert::TryFrom;
+
 /// Returns the variable length integer of the given value.
 /// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
 pub fn variable_length_integer(value: u64) -> Vec<u8> {
@@ -20,42 +24,55 @@ pub fn variable_length_integer(value: u64) -> Vec<u8> {
 
 /// Decode the value of a variable length integer.
 /// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
-pub fn read_variable_length_integer<R: Read>(mut reader: R) -> IoResult<usize> {
+pub fn read_variable_length_integer<R: Read>(reader: R) -> IoResult<usize> {
+    read_variable_length_integer_as(reader)
+}
+
+/// Decode the value of a variable length integer into `T`, rejecting values that do not fit,
+/// so that a wide encoding can never truncate to the value of a shorter one.
+fn read_variable_length_integer_as<T: TryFrom<u64>, R: Read>(mut reader: R) -> IoResult<T> {
     let mut flag = [0u8; 1];
-    reader.read(&mut flag)?;
+    reader.read_exact(&mut flag)?;
 
-    match flag[0] {
-        0..=252 => Ok(flag[0] as usize),
+    let value = match flag[0] {
+        0..=252 => u64::from(flag[0]),
         0xfd => {
             let mut size = [0u8; 2];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u16::from_le_bytes(size) {
-                s if s < 253 => Err(error("Invalid variable size integer")),
-                s => Ok(s as usize),
+                s if s < 253 => return Err(error("Invalid variable size integer")),
+                s => u64::from(s),
             }
         }
         0xfe => {
             let mut size = [0u8; 4];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u32::from_le_bytes(size) {
-                s if s < 65536 => Err(error("Invalid variable size integer")),
-                s => Ok(s as usize),
+                s if s < 65536 => return Err(error("Invalid variable size integer")),
+                s => u64::from(s),
             }
         }
         _ => {
             let mut size = [0u8; 8];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u64::from_le_bytes(size) {
-                s if s < 4_294_967_296 => Err(error("Invalid variable size integer")),
-                s => Ok(s as usize),
+                s if s < 4_294_967_296 => return Err(error("Invalid variable size integer")),
+                s => s,
             }
         }
-    }
+    };
+
+    T::try_from(value).map_err(|_| error("Invalid variable size integer"))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::rand::test_rng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 10000;
 
     const LENGTH_VALUES: [(u64, [u8; 9]); 14] = [
         (20, [0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
@@ -95,4 +112,59 @@ mod test {
             assert_eq!(*expected_size as usize, size);
         });
     }
+
+    #[test]
+    fn test_read_variable_length_integer_non_minimal() {
+        let non_minimal: [&[u8]; 4] = [
+            &[0xfd, 0xfc, 0x00],
+            &[0xfe, 0xff, 0xff, 0x00, 0x00],
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00],
+            &[0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        ];
+        for bytes in non_minimal.iter() {
+            assert!(read_variable_length_integer(*bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_read_variable_length_integer_overflow() {
+        // Widths that exceed the target type must be rejected, not truncated.
+        let wide: &[u8] = &[0xff, 0x05, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+        assert!(read_variable_length_integer_as::<u32, _>(wide).is_err());
+        assert_eq!(0x1_0000_0005, read_variable_length_integer_as::<u64, _>(wide).unwrap());
+
+        let wide: &[u8] = &[0xfe, 0x05, 0x00, 0x01, 0x00];
+        assert!(read_variable_length_integer_as::<u16, _>(wide).is_err());
+        assert_eq!(0x1_0005, read_variable_length_integer_as::<u32, _>(wide).unwrap());
+
+        let max: &[u8] = &[0xfd, 0xff, 0xff];
+        assert_eq!(u16::MAX, read_variable_length_integer_as::<u16, _>(max).unwrap());
+    }
+
+    #[test]
+    fn test_read_variable_length_integer_mutated() {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Shift by a random amount so that every encoding width is covered.
+            let value = rng.gen::<u64>() >> rng.gen_range(0, 64);
+            let mut bytes = variable_length_integer(value);
+            assert_eq!(
+                read_variable_length_integer(&bytes[..]).ok(),
+                usize::try_from(value).ok()
+            );
+
+            // Any truncation of a valid encoding must be rejected.
+            let truncated = &bytes[..rng.gen_range(0, bytes.len())];
+            assert!(read_variable_length_integer(truncated).is_err());
+
+            // A mutated encoding must be rejected or start with the canonical encoding of the decoded value.
+            let index = rng.gen_range(0, bytes.len());
+            bytes[index] = rng.gen();
+            if let Ok(size) = read_variable_length_integer(&bytes[..]) {
+                let encoded = variable_length_integer(size as u64);
+                assert_eq!(&bytes[..encoded.len()], &encoded[..]);
+            }
+        }
+    }
 }